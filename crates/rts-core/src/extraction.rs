@@ -115,6 +115,8 @@ pub(crate) fn extract_rust_symbols(
                     "private"
                 };
 
+                let docs = extract_rust_doc_comments(content, struct_node.start_position().row);
+
                 symbols.push(Symbol {
                     name: name.to_string(),
                     kind: "struct".to_string(),
@@ -123,7 +125,7 @@ pub(crate) fn extract_rust_symbols(
                     start_column: struct_node.start_position().column,
                     end_column: struct_node.end_position().column,
                     visibility: visibility.to_string(),
-                    documentation: None,
+                    documentation: docs,
                     parent: None,
                 });
             }
@@ -145,6 +147,8 @@ pub(crate) fn extract_rust_symbols(
                     "private"
                 };
 
+                let docs = extract_rust_doc_comments(content, enum_node.start_position().row);
+
                 symbols.push(Symbol {
                     name: name.to_string(),
                     kind: "enum".to_string(),
@@ -153,7 +157,7 @@ pub(crate) fn extract_rust_symbols(
                     start_column: enum_node.start_position().column,
                     end_column: enum_node.end_position().column,
                     visibility: visibility.to_string(),
-                    documentation: None,
+                    documentation: docs,
                     parent: None,
                 });
             }
@@ -1125,7 +1129,12 @@ pub(crate) fn extract_markdown_symbols(
     markdown::extract(tree, content, symbols)
 }
 
-/// Extract doc comments preceding a Rust item start line
+/// Extract doc comments preceding a Rust item start line.
+///
+/// Single-line outer attributes (`#[derive(..)]`, `#[inline]`) between
+/// the `///` block and the item are skipped: tree-sitter-rust keeps
+/// them as sibling `attribute_item` nodes, so the item's start row is
+/// below them.
 pub(crate) fn extract_rust_doc_comments(content: &str, start_row: usize) -> Option<String> {
     let lines: Vec<&str> = content.lines().collect();
     if start_row == 0 {
@@ -1138,7 +1147,7 @@ pub(crate) fn extract_rust_doc_comments(content: &str, start_row: usize) -> Opti
         let line = lines[line_idx as usize].trim();
         if line.starts_with("///") {
             docs.push(line.trim_start_matches("///").trim());
-        } else if line.is_empty() {
+        } else if line.is_empty() || (line.starts_with("#[") && line.ends_with(']')) {
             line_idx -= 1;
             continue;
         } else {
@@ -1646,6 +1655,30 @@ mod tests {
         );
     }
 
+    /// Rust `///` docs reach structs and enums, and survive attributes
+    /// sitting between the comment and the item.
+    #[test]
+    fn rust_doc_comments_skip_attributes() {
+        let src = "/// A point.\n#[derive(Debug, Clone)]\npub struct Point { x: i32 }\n\n\
+                   /// Shapes.\n#[non_exhaustive]\npub enum Shape { Circle }\n\n\
+                   /// Adds one.\n#[inline]\n#[must_use]\npub fn inc(x: i32) -> i32 { x + 1 }\n\n\
+                   pub struct Bare;\n";
+        let outcome = parse_content(src, Language::Rust).unwrap();
+        let doc = |name: &str| {
+            outcome
+                .symbols
+                .iter()
+                .find(|s| s.name == name)
+                .unwrap_or_else(|| panic!("{name} should be extracted"))
+                .documentation
+                .clone()
+        };
+        assert_eq!(doc("Point").as_deref(), Some("A point."));
+        assert_eq!(doc("Shape").as_deref(), Some("Shapes."));
+        assert_eq!(doc("inc").as_deref(), Some("Adds one."));
+        assert_eq!(doc("Bare"), None);
+    }
+
     /// JSDoc /** ... */ blocks should flow through to
     /// `Symbol::documentation`. The cosmetic `*` on continuation lines
     /// is stripped.
@@ -72,25 +72,30 @@ pub use verify::{
 /// Outcome of [`parse_content`].
 ///
 /// `symbols` carries the extracted symbol records; `partial_errors`
-/// carries human-readable notes about known incomplete extraction
-/// paths (e.g. Java/C/C++ may successfully parse but currently
-/// return an empty `Vec<Symbol>` via TODO-stubbed extractors —
-/// surfacing the gap here lets callers distinguish "no symbols in
-/// this file" from "extractor doesn't cover this language yet"
-/// without re-parsing).
+/// carries human-readable diagnostics for the syntax errors
+/// tree-sitter recovered from while parsing. Tree-sitter never
+/// rejects input outright — it wraps unparseable spans in `ERROR`
+/// nodes and synthesizes `MISSING` tokens — so a non-empty list means
+/// "symbols were extracted, but the spans listed here may be missing
+/// some". Callers use it to tell a clean parse from a recovered one
+/// without re-parsing.
 ///
-/// Today the field is always empty because the extractors don't
-/// yet self-report; the type reserves the slot so we don't need a
-/// breaking change when they do.
+/// Each entry reads `"<line>:<column>: <message>"` (1-based line and
+/// column). The list is capped at [`MAX_PARTIAL_ERRORS`]; when the
+/// cap is hit the final entry summarizes how many were dropped.
 #[derive(Debug, Clone, Default)]
 pub struct ParseOutcome {
     /// Symbols extracted from the parse tree, in source order.
     pub symbols: Vec<Symbol>,
-    /// Non-fatal extraction warnings (e.g. "Java extractor is a stub").
-    /// Empty under v0; reserved for future extractor self-reporting.
+    /// Recovered syntax errors, in source order. Empty on a clean parse.
     pub partial_errors: Vec<String>,
 }
 
+/// Upper bound on [`ParseOutcome::partial_errors`] entries. A badly
+/// mangled file can produce thousands of `ERROR` nodes; past a few
+/// dozen the extra locations stop being actionable.
+pub const MAX_PARTIAL_ERRORS: usize = 32;
+
 /// Parse `content` for the given `language` and extract symbols.
 ///
 /// Built from primitives (`Parser::new` + `extraction::extract_symbols`)
@@ -122,10 +127,68 @@ pub fn parse_content(content: &str, language: Language) -> Result<ParseOutcome>
     let symbols = extraction::extract_symbols(&tree, content, language)?;
     Ok(ParseOutcome {
         symbols,
-        partial_errors: Vec::new(),
+        partial_errors: syntax_diagnostics(&tree),
     })
 }
 
+/// Render the tree's `ERROR` / `MISSING` nodes as `partial_errors`
+/// entries. Nested `ERROR` nodes are reported once, at the outermost
+/// span, so one bad token doesn't fan out into a dozen entries.
+fn syntax_diagnostics(tree: &SyntaxTree) -> Vec<String> {
+    if !tree.has_error() {
+        return Vec::new();
+    }
+    let nodes: Vec<Node<'_>> = tree
+        .error_nodes()
+        .into_iter()
+        .filter(|node| {
+            let mut ancestor = node.parent();
+            while let Some(a) = ancestor {
+                if a.is_error() {
+                    return false;
+                }
+                ancestor = a.parent();
+            }
+            true
+        })
+        .collect();
+
+    let mut out: Vec<String> = nodes
+        .iter()
+        .take(MAX_PARTIAL_ERRORS)
+        .map(|node| {
+            let pos = node.start_position();
+            let message = if node.is_missing() {
+                format!("missing `{}`", node.kind())
+            } else {
+                let snippet: String = node
+                    .text()
+                    .unwrap_or("")
+                    .lines()
+                    .next()
+                    .unwrap_or("")
+                    .trim()
+                    .chars()
+                    .take(40)
+                    .collect();
+                if snippet.is_empty() {
+                    "syntax error".to_string()
+                } else {
+                    format!("syntax error near `{snippet}`")
+                }
+            };
+            format!("{}:{}: {message}", pos.row + 1, pos.column + 1)
+        })
+        .collect();
+    if nodes.len() > MAX_PARTIAL_ERRORS {
+        out.push(format!(
+            "... {} more syntax errors not shown",
+            nodes.len() - MAX_PARTIAL_ERRORS
+        ));
+    }
+    out
+}
+
 // Utilities
 pub use constants::common::RiskLevel;
 
@@ -335,6 +398,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_content_reports_recovered_syntax_errors() {
+        // `broken` is missing its closing paren; tree-sitter recovers
+        // and `fine` must still be extracted.
+        let src = "fn broken(a: i32 {\n}\n\nfn fine() {}\n";
+        let outcome = parse_content(src, Language::Rust).unwrap();
+        assert!(
+            outcome.symbols.iter().any(|s| s.name == "fine"),
+            "recovery should keep later symbols; got {:?}",
+            outcome.symbols.iter().map(|s| &s.name).collect::<Vec<_>>()
+        );
+        assert!(
+            !outcome.partial_errors.is_empty(),
+            "expected a syntax diagnostic for the unclosed parameter list"
+        );
+        assert!(
+            outcome.partial_errors[0].starts_with("1:"),
+            "first diagnostic should point at line 1; got {:?}",
+            outcome.partial_errors
+        );
+    }
+
+    #[test]
+    fn parse_content_caps_partial_errors() {
+        let src = "fn f() { let x = ; }\n".repeat(MAX_PARTIAL_ERRORS * 2);
+        let outcome = parse_content(&src, Language::Rust).unwrap();
+        assert_eq!(
+            outcome.partial_errors.len(),
+            MAX_PARTIAL_ERRORS + 1,
+            "expected the cap plus one summary entry; got {:?}",
+            outcome.partial_errors
+        );
+        assert!(
+            outcome
+                .partial_errors
+                .last()
+                .unwrap()
+                .contains("more syntax errors"),
+            "summary entry missing: {:?}",
+            outcome.partial_errors.last()
+        );
+    }
+
     #[test]
     fn parse_content_caller_excludes_called_fn_names() {
         // Regression: a naïve "first identifier descendant" pattern
@@ -5,7 +5,9 @@ use crate::languages::Language;
 use crate::tree::SyntaxTree;
 use lru::LruCache;
 use std::num::NonZeroUsize;
+use std::ops::ControlFlow;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tree_sitter::{InputEdit, Point};
 
 /// Default capacity for the parsed-tree cache.
@@ -20,7 +22,8 @@ const DEFAULT_TREE_CACHE_SIZE: usize = 100;
 pub struct ParseOptions {
     /// Maximum number of bytes to parse (None for unlimited)
     pub max_bytes: Option<usize>,
-    /// Timeout for parsing in milliseconds (None for no timeout)
+    /// Timeout for parsing in milliseconds (None for no timeout). A parse
+    /// that runs past it is cancelled and returns a parse error.
     pub timeout_millis: Option<u64>,
     /// Whether to include extra information in the tree
     pub include_extras: bool,
@@ -121,20 +124,42 @@ impl Parser {
             Error::internal_error("parser", format!("Failed to acquire parser lock: {}", e))
         })?;
 
-        // Note: `Parser::set_timeout_micros` was removed in tree-sitter 0.26.
-        // The replacement is `ParseOptions::progress_callback(cb)` returning
-        // `ControlFlow::Break` to cancel parsing. Cooperative-timeout support is
-        // a follow-up task; the historical `options.timeout_millis` is currently
-        // a no-op. Tracked in the P1 migration notes.
-        let _ = self.options.timeout_millis;
-
         // Convert old tree if provided
         let old_ts_tree = old_tree.map(|t| t.inner());
 
-        // Parse the source
-        let tree = parser
-            .parse(source, old_ts_tree)
-            .ok_or_else(|| Error::parse_error("Failed to parse source code"))?;
+        // Parse the source. tree-sitter 0.26 dropped `set_timeout_micros`;
+        // the timeout is enforced by a progress callback that breaks once
+        // the deadline passes.
+        let tree = match self.options.timeout_millis {
+            Some(ms) => {
+                let deadline = Instant::now() + Duration::from_millis(ms);
+                let mut progress = |_: &tree_sitter::ParseState| {
+                    if Instant::now() >= deadline {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                };
+                let bytes = source.as_bytes();
+                let mut read = |offset: usize, _: Point| bytes.get(offset..).unwrap_or_default();
+                let options = tree_sitter::ParseOptions::new().progress_callback(&mut progress);
+                match parser.parse_with_options(&mut read, old_ts_tree, Some(options)) {
+                    Some(tree) => tree,
+                    None => {
+                        // A cancelled parse leaves its state in the parser
+                        // for resumption; drop it so the next call starts
+                        // fresh.
+                        parser.reset();
+                        return Err(Error::parse_error(format!(
+                            "parse exceeded the {ms} ms timeout"
+                        )));
+                    }
+                }
+            }
+            None => parser
+                .parse(source, old_ts_tree)
+                .ok_or_else(|| Error::parse_error("Failed to parse source code"))?,
+        };
 
         // Note: We allow trees with errors to be returned, as they can still be useful
         // The caller can check tree.has_error() if they need to know about parse errors
@@ -341,6 +366,29 @@ mod tests {
         assert!(!parser.options().include_extras);
     }
 
+    #[test]
+    fn test_parse_timeout_cancels_and_resets() {
+        let options = ParseOptions {
+            timeout_millis: Some(0),
+            ..ParseOptions::default()
+        };
+        let mut parser = Parser::with_options(Language::Rust, options).unwrap();
+        let big = "fn f() { let x = 1 + 2 * 3; }\n".repeat(20_000);
+        let Err(err) = parser.parse(&big, None) else {
+            panic!("a 0 ms budget must cancel the parse");
+        };
+        assert!(err.to_string().contains("timeout"), "got {err}");
+
+        // The cancelled parse must not leak into the next one.
+        parser.set_options(ParseOptions {
+            timeout_millis: None,
+            ..ParseOptions::default()
+        });
+        let tree = parser.parse("struct S;", None).unwrap();
+        assert_eq!(tree.root_node().kind(), "source_file");
+        assert!(!tree.has_error());
+    }
+
     #[test]
     fn test_parser_clone() {
         let parser1 = Parser::new(Language::Rust).unwrap();
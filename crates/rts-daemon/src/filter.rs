@@ -5,7 +5,10 @@
 //!
 //! The filter is *deterministic and cheap*: it never opens the file. Content
 //! scanning (high-entropy / known-token regexes — §13.2) happens at index time
-//! in a later P6 phase, not here.
+//! in a later P6 phase, not here. The one content-level check that does live
+//! here, [`generated_content`], is a pure function over bytes the writer has
+//! already read; it's co-located so the path- and content-side "is this
+//! machine-generated?" rules stay in one place.
 
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
@@ -40,6 +43,11 @@ pub enum SkipReason {
     /// through the watcher in storms; filtering pre-debouncer is a hot-path
     /// concern.
     EditorSwap,
+    /// Lockfile or build artifact recognised by name
+    /// (`package-lock.json`, `pnpm-lock.yaml`, `*.min.js`, …). Indexing these floods search
+    /// results with noise no agent wants; `RTS_INDEX_GENERATED=1` opts
+    /// back in.
+    Generated,
     /// File is symlinked outside the workspace root. Reserved for the
     /// content-scanner path that would enforce §6.2's per-read prefix check
     /// at index time — the watcher currently doesn't stat to detect this,
@@ -104,6 +112,112 @@ fn editor_swap_regex() -> &'static Regex {
     })
 }
 
+/// Env var that disables generated-file detection (both the path rule in
+/// [`classify`] and the writer's [`generated_content`] check). Set to `1`
+/// for workspaces that deliberately want vendored bundles or generated
+/// bindings in the index.
+pub const INDEX_GENERATED_ENV: &str = "RTS_INDEX_GENERATED";
+
+/// Whether generated-file detection is active. Read once per process —
+/// `classify` runs on every watcher event, so no per-call env lookup.
+pub fn generated_filter_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        !matches!(
+            std::env::var(INDEX_GENERATED_ENV).as_deref(),
+            Ok("1") | Ok("true")
+        )
+    })
+}
+
+/// Lockfiles and minified bundles, matched on the whole path. Only names
+/// whose extension would otherwise pass the allowlist need listing —
+/// `Cargo.lock`, `yarn.lock`, `go.sum` and friends already fall to
+/// `UnsupportedExtension`.
+fn generated_path_regex() -> &'static Regex {
+    static R: OnceLock<Regex> = OnceLock::new();
+    R.get_or_init(|| {
+        Regex::new(
+            r"(?x)
+              (^|/)(package-lock|npm-shrinkwrap)\.json$
+            | (^|/)pnpm-lock\.ya?ml$
+            | \.min\.(js|mjs|cjs)$
+            | \.bundle\.js$
+            ",
+        )
+        .expect("generated path regex compiles")
+    })
+}
+
+/// Why [`generated_content`] flagged a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratedReason {
+    /// A NUL byte in the first 8 KiB — binary data that happens to be
+    /// valid UTF-8.
+    Binary,
+    /// A generator marker (`@generated`, `<auto-generated>`, or Go's
+    /// `// Code generated … DO NOT EDIT.`) near the top of the file.
+    Marker,
+    /// Mean line length above [`MINIFIED_MEAN_LINE_BYTES`].
+    Minified,
+}
+
+/// Lines this long on average mean a minifier or code generator emitted
+/// the file; hand-written code sits well under 100.
+const MINIFIED_MEAN_LINE_BYTES: usize = 500;
+
+/// How many leading lines are searched for a generator marker. Generators
+/// put the banner first; scanning further mostly finds prose *about*
+/// generated files.
+const MARKER_SCAN_LINES: usize = 5;
+
+/// Inspect already-read file content for signs it was machine-generated.
+///
+/// `@generated` and `<auto-generated` only count on comment lines (`//`,
+/// `#`, `/*`, `*`, `<!--`, `--`); the Go banner must be the whole line.
+/// Markdown gets the binary check only: docs routinely *mention* the
+/// markers, and unwrapped prose paragraphs look minified by line length
+/// alone.
+pub fn generated_content(content: &str, is_markdown: bool) -> Option<GeneratedReason> {
+    let head = &content.as_bytes()[..content.len().min(8 * 1024)];
+    if head.contains(&0) {
+        return Some(GeneratedReason::Binary);
+    }
+    if is_markdown {
+        return None;
+    }
+
+    let marker = content.lines().take(MARKER_SCAN_LINES).any(|line| {
+        if is_go_generated_banner(line) {
+            return true;
+        }
+        let t = line.trim_start();
+        let is_comment = ["//", "#", "/*", "*", "<!--", "--"]
+            .iter()
+            .any(|leader| t.starts_with(leader));
+        is_comment && (t.contains("@generated") || t.contains("<auto-generated"))
+    });
+    if marker {
+        return Some(GeneratedReason::Marker);
+    }
+
+    if content.len() >= 1024 {
+        let lines = content.lines().count().max(1);
+        if content.len() / lines > MINIFIED_MEAN_LINE_BYTES {
+            return Some(GeneratedReason::Minified);
+        }
+    }
+    None
+}
+
+/// The Go convention (`^// Code generated .* DO NOT EDIT\.$`), which
+/// protoc, stringer and most other generators follow. A bare
+/// "DO NOT EDIT" is not enough: hand-written headers use it too.
+fn is_go_generated_banner(line: &str) -> bool {
+    line.strip_prefix("// Code generated ")
+        .is_some_and(|rest| rest.ends_with(" DO NOT EDIT."))
+}
+
 /// Per protocol-v0 §13.4: extensions whose body content may be returned to an
 /// agent. Other indexed files get `IndexSignatureOnly`.
 pub const BODY_ALLOWED_EXTENSIONS: &[&str] = &[
@@ -116,9 +230,10 @@ pub const BODY_ALLOWED_EXTENSIONS: &[&str] = &[
 // v0.7.0: per-file 4 MB byte cap on markdown is already provided by the
 // existing global `OVERSIZE_THRESHOLD_BYTES` in `writer.rs` (4 MiB).
 // Files exceeding the threshold are tagged `oversize=true` and skip
-// symbol extraction entirely — that's the parser-DoS guard the plan
-// calls out (`set_timeout_micros` is a no-op in tree-sitter ≥ 0.26).
-// No separate markdown-specific constant needed.
+// symbol extraction entirely. Below the cap, a pathological parse is
+// cancelled after 5 s (`ParseOptions::timeout_millis`, enforced in
+// `Parser::parse`) and lands in `parse_failed_files`. No separate
+// markdown-specific constant needed.
 
 /// Extensions we *index* (structure / symbols) but never return body for.
 /// Empty for v0 — anything outside the body-allowlist is skipped entirely. A
@@ -129,7 +244,8 @@ const SIGNATURE_ONLY_EXTENSIONS: &[&str] = &[];
 /// 1. editor-swap regex (cheapest, most common skip)
 /// 2. extension allowlist (cheap; eliminates binaries fast)
 /// 3. secrets blocklist regex (cheap; rarely matches)
-/// 4. gitignore matcher (callers provide the prebuilt matcher; we don't
+/// 4. generated-path regex (lockfiles, minified bundles)
+/// 5. gitignore matcher (callers provide the prebuilt matcher; we don't
 ///    rebuild per-call).
 pub fn classify(path: &Path, gitignore: &PrebuiltGitignore) -> FilterDecision {
     // We do *not* call `symlink_metadata` here — the watcher can race with
@@ -158,6 +274,10 @@ pub fn classify(path: &Path, gitignore: &PrebuiltGitignore) -> FilterDecision {
         return FilterDecision::Skip(SkipReason::UnsupportedExtension);
     }
 
+    if generated_filter_enabled() && generated_path_regex().is_match(display_ref) {
+        return FilterDecision::Skip(SkipReason::Generated);
+    }
+
     // gitignore check last: it's the only filter that allocates a Vec for
     // path-component traversal.
     if gitignore.is_ignored(path) {
@@ -340,6 +460,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn generated_filenames_skip() {
+        let g = empty_gitignore();
+        for generated in [
+            "package-lock.json",
+            "web/npm-shrinkwrap.json",
+            "pnpm-lock.yaml",
+            "static/app.min.js",
+            "dist-assets/vendor.bundle.js",
+        ] {
+            let p = g.root.join(generated);
+            assert_eq!(
+                classify(&p, &g),
+                FilterDecision::Skip(SkipReason::Generated),
+                "{generated} should be classified as generated"
+            );
+        }
+        // Near-misses stay indexed.
+        for fine in ["package.json", "src/minify.js", "lock.rs"] {
+            let p = g.root.join(fine);
+            assert_eq!(classify(&p, &g), FilterDecision::IndexFull, "{fine}");
+        }
+    }
+
+    #[test]
+    fn generated_content_detects_binary_marker_and_minified() {
+        assert_eq!(
+            generated_content("fn main() {}\0\0", false),
+            Some(GeneratedReason::Binary)
+        );
+        assert_eq!(
+            generated_content(
+                "// Code generated by protoc-gen-go. DO NOT EDIT.\npackage pb\n",
+                false
+            ),
+            Some(GeneratedReason::Marker)
+        );
+        assert_eq!(
+            generated_content("# @generated by build.py\nx = 1\n", false),
+            Some(GeneratedReason::Marker)
+        );
+        let minified = format!("var a={};\n", "b".repeat(4096));
+        assert_eq!(
+            generated_content(&minified, false),
+            Some(GeneratedReason::Minified)
+        );
+    }
+
+    #[test]
+    fn generated_content_ignores_prose_and_late_mentions() {
+        // Markdown talking about markers is not generated, and neither
+        // is an unwrapped paragraph.
+        assert_eq!(
+            generated_content(
+                "# Skipping @generated files\n\nDO NOT EDIT banners…\n",
+                true
+            ),
+            None
+        );
+        assert_eq!(generated_content(&"word ".repeat(400), true), None);
+        // A marker in a string literal, not a comment.
+        assert_eq!(
+            generated_content("const BANNER: &str = \"@generated\";\n", false),
+            None
+        );
+        // Hand-written "do not edit" headers are not generator banners.
+        assert_eq!(
+            generated_content(
+                "// DO NOT EDIT without updating the schema\nfn f() {}\n",
+                false
+            ),
+            None
+        );
+        assert_eq!(
+            generated_content("// Code generated by hand. DO NOT EDIT lightly\n", false),
+            None
+        );
+        // A marker well past the banner window.
+        let late = format!("{}// @generated\n", "fn f() {}\n".repeat(10));
+        assert_eq!(generated_content(&late, false), None);
+    }
+
     #[test]
     fn gitignore_excludes_target_dir() {
         // PrebuiltGitignore adds `target/` as a fallback pattern. We use a
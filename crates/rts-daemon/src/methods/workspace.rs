@@ -568,6 +568,7 @@ pub async fn status(
             "progress":         { "files_done": 0, "files_total": 0, "phase": "no_mount" },
             "index_generation": state.index_generation.load(Ordering::Relaxed),
             "parse_failed_files": 0,
+            "parse_partial_files": 0,
             "watcher_status":   state.watcher_status().as_wire_str(),
             "uptime_ms":        state.uptime().as_millis() as u64,
             "memory_rss_bytes": 0
@@ -651,6 +652,7 @@ fn status_payload(
         // The fields below show up in Status but not Mount in the spec; emit
         // them both places so a client can use either response interchangeably.
         "parse_failed_files": store_stats.parse_failed_files,
+        "parse_partial_files": store_stats.parse_partial_files,
         "watcher_status":     state.watcher_status().as_wire_str(),
         "uptime_ms":          state.uptime().as_millis() as u64,
         "memory_rss_bytes":   0
@@ -80,6 +80,9 @@ pub enum FileRejected {
 pub struct StoreStats {
     pub files_indexed: u64,
     pub parse_failed_files: u64,
+    /// Files that parsed with recovered syntax errors
+    /// (`ParseStatus::Partial`, excluding oversize stubs).
+    pub parse_partial_files: u64,
 }
 
 /// A single reference hit extracted from a parse pass. The writer
@@ -237,12 +240,29 @@ impl Store {
             w.commit().context("commit table init")?;
         }
 
+        // A warm mount reuses the on-disk rows, so the parse-status
+        // counters start from what the FILES table already holds rather
+        // than zero; `commit_batch` applies deltas on top.
+        let mut stats = StoreStats::default();
+        {
+            let txn = db.begin_read().context("begin_read for stats seed")?;
+            let files = txn.open_table(FILES)?;
+            for row in files.iter()? {
+                let (_, v) = row?;
+                let Ok(m) = from_bytes::<FileMeta>(v.value()) else {
+                    continue;
+                };
+                stats.parse_failed_files += u64::from(m.parse_status == ParseStatus::Failed);
+                stats.parse_partial_files += u64::from(is_recovered_parse(m));
+            }
+        }
+
         Ok(Self {
             db,
             db_path: db_path.to_path_buf(),
             next_fid: AtomicU32::new(next_fid),
             next_sid: AtomicU32::new(next_sid),
-            stats: std::sync::Mutex::new(StoreStats::default()),
+            stats: std::sync::Mutex::new(stats),
         })
     }
 
@@ -270,6 +290,7 @@ impl Store {
         }
         let mut indexed = 0u64;
         let mut parse_failed_delta = 0i64;
+        let mut parse_partial_delta = 0i64;
 
         let mut txn = self.db.begin_write().context("begin_write")?;
         txn.set_durability(durability);
@@ -299,6 +320,17 @@ impl Store {
                     Some(v) => v.value(),
                     None => continue,
                 };
+                let prior_meta = files
+                    .get(&fid)?
+                    .and_then(|v| from_bytes::<FileMeta>(v.value()).ok());
+                if let Some(m) = prior_meta {
+                    if m.parse_status == ParseStatus::Failed {
+                        parse_failed_delta -= 1;
+                    }
+                    if is_recovered_parse(m) {
+                        parse_partial_delta -= 1;
+                    }
+                }
                 drop_file_entries(
                     &mut files,
                     &mut fid_defs,
@@ -355,9 +387,10 @@ impl Store {
                 };
 
                 // Track parse-status delta for stats.
-                let prior_failed = files
+                let prior_meta = files
                     .get(&fid)?
-                    .and_then(|v| from_bytes::<FileMeta>(v.value()).ok())
+                    .and_then(|v| from_bytes::<FileMeta>(v.value()).ok());
+                let prior_failed = prior_meta
                     .map(|m| m.parse_status == ParseStatus::Failed)
                     .unwrap_or(false);
                 let now_failed = entry.meta.parse_status == ParseStatus::Failed;
@@ -366,6 +399,13 @@ impl Store {
                 } else if !now_failed && prior_failed {
                     parse_failed_delta -= 1;
                 }
+                let prior_partial = prior_meta.map(is_recovered_parse).unwrap_or(false);
+                let now_partial = is_recovered_parse(entry.meta);
+                if now_partial && !prior_partial {
+                    parse_partial_delta += 1;
+                } else if !now_partial && prior_partial {
+                    parse_partial_delta -= 1;
+                }
 
                 // Drop prior defs *and* refs for this file before re-inserting;
                 // this is the simplest correct policy and avoids stale-symbol
@@ -563,6 +603,8 @@ impl Store {
         if let Ok(mut s) = self.stats.lock() {
             s.files_indexed += indexed;
             s.parse_failed_files = (s.parse_failed_files as i64 + parse_failed_delta).max(0) as u64;
+            s.parse_partial_files =
+                (s.parse_partial_files as i64 + parse_partial_delta).max(0) as u64;
         }
 
         Ok(indexed)
@@ -1929,6 +1971,13 @@ fn is_call_bearing_kind(kind: SymbolKind) -> bool {
     )
 }
 
+/// Whether a file parsed with recovered syntax errors. Oversize stubs
+/// also carry `ParseStatus::Partial` but were never parsed, so they
+/// don't count toward `parse_partial_files`.
+fn is_recovered_parse(meta: FileMeta) -> bool {
+    meta.parse_status == ParseStatus::Partial && !meta.oversize
+}
+
 fn u32_from_le_slice(slice: &[u8]) -> Option<u32> {
     if slice.len() == 4 {
         Some(u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]))
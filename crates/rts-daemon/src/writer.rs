@@ -19,7 +19,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use redb::Durability;
-use rust_tree_sitter::{Language, Symbol};
+use rust_tree_sitter::{Language, ParseOutcome, Symbol};
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
@@ -334,6 +334,12 @@ fn flush(
                 // File vanished between event and parse. Treat as removal.
                 removals.insert(path, ());
             }
+            Err(ParseRejected::Generated(reason)) => {
+                // Drop any entries from before the file became generated
+                // (e.g. a hand-written file replaced by a build output).
+                debug!(path = %path.display(), ?reason, "skipped generated file");
+                removals.insert(path, ());
+            }
             Err(other) => {
                 debug!(path = %path.display(), reason = ?other, "skipped during parse");
             }
@@ -500,6 +506,7 @@ fn rescan_and_reconcile(
 #[derive(Debug)]
 enum ParseRejected {
     IoMissing,
+    Generated(crate::filter::GeneratedReason),
     Oversize,
     UnsupportedLanguage,
     OutOfRoot,
@@ -570,10 +577,16 @@ fn parse_and_extract(
         Ok(s) => s,
         Err(_) => return Err(ParseRejected::IoMissing),
     };
+    if crate::filter::generated_filter_enabled() {
+        let is_markdown = language == Language::Markdown;
+        if let Some(reason) = crate::filter::generated_content(&content, is_markdown) {
+            return Err(ParseRejected::Generated(reason));
+        }
+    }
     let content_hash: [u8; 32] = blake3::hash(content.as_bytes()).into();
 
-    let symbols = match parsers.parse_and_extract(language, &content) {
-        Ok(s) => s,
+    let outcome = match parsers.parse_and_extract(language, &content) {
+        Ok(o) => o,
         Err(e) => {
             warn!(path = %abs_path.display(), error = %e, "parse failed");
             // Still record the file so re-saves can clear the failure.
@@ -593,6 +606,22 @@ fn parse_and_extract(
         }
     };
 
+    // Tree-sitter recovered from syntax errors: the defs we did get
+    // are real, but spans inside the ERROR nodes may be missing some.
+    // Record the file as `Partial` so `Workspace.Status` can count it.
+    let parse_status = if outcome.partial_errors.is_empty() {
+        ParseStatus::Ok
+    } else {
+        debug!(
+            path = %abs_path.display(),
+            errors = outcome.partial_errors.len(),
+            first = %outcome.partial_errors[0],
+            "parsed with recovered syntax errors"
+        );
+        ParseStatus::Partial
+    };
+    let symbols = outcome.symbols;
+
     // Collect docs alongside defs in one pass — `symbol_to_def` strips
     // the rts-core Symbol after extraction, so we read the doc field
     // out before that. Empty doc strings are filtered to avoid noise
@@ -629,7 +658,7 @@ fn parse_and_extract(
             content_hash,
             mtime_ns,
             lang: lang_tag(language),
-            parse_status: ParseStatus::Ok,
+            parse_status,
             oversize: false,
         },
         defs,
@@ -758,15 +787,15 @@ impl ParserPool {
         Self
     }
 
-    /// Parse `content` for `language` and return the extracted symbols.
+    /// Parse `content` for `language` and return the extracted symbols
+    /// plus any recovered syntax errors.
     ///
     /// Bypasses the filesystem entirely — content goes straight into
     /// `rust_tree_sitter::parse_content`, which parses with tree-sitter
     /// and runs the per-language symbol extractor. No tempfile, no
     /// disk round-trip.
-    fn parse_and_extract(&self, language: Language, content: &str) -> anyhow::Result<Vec<Symbol>> {
-        let outcome = rust_tree_sitter::parse_content(content, language)?;
-        Ok(outcome.symbols)
+    fn parse_and_extract(&self, language: Language, content: &str) -> anyhow::Result<ParseOutcome> {
+        Ok(rust_tree_sitter::parse_content(content, language)?)
     }
 }
 
@@ -778,7 +807,7 @@ mod tests {
     fn parse_and_extract_returns_rust_symbols() {
         let pool = ParserPool::new();
         let src = "pub fn hello() {}\npub struct World;\n";
-        let syms = pool.parse_and_extract(Language::Rust, src).unwrap();
+        let syms = pool.parse_and_extract(Language::Rust, src).unwrap().symbols;
         assert!(
             syms.iter().any(|s| s.name == "hello"),
             "expected `hello` in {syms:?}"
@@ -792,7 +821,7 @@ mod tests {
         let pool = ParserPool::new();
         let src =
             "pub fn caller_a_one() {\n    let _ = hub_compute(1);\n    let _ = hub_format(2);\n}\n";
-        let syms = pool.parse_and_extract(Language::Rust, src).unwrap();
+        let syms = pool.parse_and_extract(Language::Rust, src).unwrap().symbols;
         let names: Vec<_> = syms.iter().map(|s| s.name.as_str()).collect();
         assert!(
             names.contains(&"caller_a_one"),
@@ -816,14 +845,15 @@ mod tests {
     /// works (covered by 22 unit tests in
     /// `rust_tree_sitter::signature::tests`), but they won't get
     /// signature-rendered through `Index.ReadSymbol` until the
-    /// extractors are filled in. Go works. Post-PR-B (B1) the
-    /// `ParseOutcome::partial_errors` slot reserves room for the
-    /// extractors to self-report this gap without a breaking change.
+    /// extractors are filled in. Go works. `ParseOutcome::partial_errors`
+    /// carries the tree's recovered syntax errors (`ERROR` / `MISSING`
+    /// nodes), not extractor gaps, so a clean Java/C/C++ parse with
+    /// empty symbols still reports no errors.
     #[test]
     fn parse_and_extract_returns_go_symbols() {
         let pool = ParserPool::new();
         let src = "package demo\n\nfunc GoTarget(name string) int { return len(name) }\n";
-        let syms = pool.parse_and_extract(Language::Go, src).unwrap();
+        let syms = pool.parse_and_extract(Language::Go, src).unwrap().symbols;
         let names: Vec<_> = syms.iter().map(|s| s.name.as_str()).collect();
         assert!(
             names.contains(&"GoTarget"),
@@ -835,7 +865,7 @@ mod tests {
     fn parse_and_extract_returns_java_symbols() {
         let pool = ParserPool::new();
         let src = "package demo;\n\npublic class JavaTarget {\n    public int compute(int x) { return x + 1; }\n}\n";
-        let syms = pool.parse_and_extract(Language::Java, src).unwrap();
+        let syms = pool.parse_and_extract(Language::Java, src).unwrap().symbols;
         let names: Vec<_> = syms.iter().map(|s| s.name.as_str()).collect();
         assert!(
             names.contains(&"JavaTarget"),
@@ -847,7 +877,7 @@ mod tests {
     fn parse_and_extract_returns_c_symbols() {
         let pool = ParserPool::new();
         let src = "int c_target(int a, int b) { return a + b; }\n";
-        let syms = pool.parse_and_extract(Language::C, src).unwrap();
+        let syms = pool.parse_and_extract(Language::C, src).unwrap().symbols;
         let names: Vec<_> = syms.iter().map(|s| s.name.as_str()).collect();
         assert!(
             names.contains(&"c_target"),
@@ -859,7 +889,7 @@ mod tests {
     fn parse_and_extract_returns_cpp_symbols() {
         let pool = ParserPool::new();
         let src = "int cpp_target(int a, int b) { return a + b; }\n";
-        let syms = pool.parse_and_extract(Language::Cpp, src).unwrap();
+        let syms = pool.parse_and_extract(Language::Cpp, src).unwrap().symbols;
         let names: Vec<_> = syms.iter().map(|s| s.name.as_str()).collect();
         assert!(
             names.contains(&"cpp_target"),
@@ -871,7 +901,7 @@ mod tests {
     fn parse_and_extract_returns_php_symbols() {
         let pool = ParserPool::new();
         let src = "<?php\nfunction phpTarget($a, $b) { return $a + $b; }\n";
-        let syms = pool.parse_and_extract(Language::Php, src).unwrap();
+        let syms = pool.parse_and_extract(Language::Php, src).unwrap().symbols;
         let names: Vec<_> = syms.iter().map(|s| s.name.as_str()).collect();
         assert!(
             names.contains(&"phpTarget"),
@@ -883,7 +913,7 @@ mod tests {
     fn parse_and_extract_returns_ruby_symbols() {
         let pool = ParserPool::new();
         let src = "def ruby_target(name)\n  name.length\nend\n";
-        let syms = pool.parse_and_extract(Language::Ruby, src).unwrap();
+        let syms = pool.parse_and_extract(Language::Ruby, src).unwrap().symbols;
         let names: Vec<_> = syms.iter().map(|s| s.name.as_str()).collect();
         assert!(
             names.contains(&"ruby_target"),
@@ -895,7 +925,10 @@ mod tests {
     fn parse_and_extract_returns_swift_symbols() {
         let pool = ParserPool::new();
         let src = "func swiftTarget(_ a: Int, _ b: Int) -> Int { return a + b }\n";
-        let syms = pool.parse_and_extract(Language::Swift, src).unwrap();
+        let syms = pool
+            .parse_and_extract(Language::Swift, src)
+            .unwrap()
+            .symbols;
         let names: Vec<_> = syms.iter().map(|s| s.name.as_str()).collect();
         assert!(
             names.contains(&"swiftTarget"),
@@ -955,6 +988,75 @@ mod tests {
         (store, tmp)
     }
 
+    #[test]
+    fn recovered_syntax_errors_mark_file_partial() {
+        let tmp = tempfile::tempdir().unwrap();
+        let db_path = tmp.path().join("_index").join("db.redb");
+        std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+        let store = Store::open(&db_path).unwrap();
+        let parsers = ParserPool::new();
+        let abs = tmp.path().join("broken.rs");
+
+        std::fs::write(&abs, "fn broken(a: i32 {\n}\n\npub fn fine() {}\n").unwrap();
+        let entry = parse_and_extract(&parsers, tmp.path(), &abs).expect("parse broken");
+        assert_eq!(entry.meta.parse_status, ParseStatus::Partial);
+        assert!(!entry.meta.oversize);
+        assert!(
+            entry.defs.iter().any(|(name, _, _)| name == "fine"),
+            "recovered parse should still index later defs"
+        );
+        store
+            .commit_batch(vec![entry], vec![], redb::Durability::Immediate)
+            .unwrap();
+        assert_eq!(store.stats().parse_partial_files, 1);
+
+        // Fixing the file clears the counter on the next commit.
+        std::fs::write(&abs, "pub fn fine() {}\n").unwrap();
+        let entry = parse_and_extract(&parsers, tmp.path(), &abs).expect("parse fixed");
+        assert_eq!(entry.meta.parse_status, ParseStatus::Ok);
+        store
+            .commit_batch(vec![entry], vec![], redb::Durability::Immediate)
+            .unwrap();
+        assert_eq!(store.stats().parse_partial_files, 0);
+
+        // Removing a still-partial file clears the counter too.
+        std::fs::write(&abs, "fn broken(a: i32 {\n}\n").unwrap();
+        let entry = parse_and_extract(&parsers, tmp.path(), &abs).expect("parse broken");
+        let path = entry.path.clone();
+        store
+            .commit_batch(vec![entry], vec![], redb::Durability::Immediate)
+            .unwrap();
+        assert_eq!(store.stats().parse_partial_files, 1);
+
+        // Reopening the store seeds the counter from the persisted rows.
+        drop(store);
+        let store = Store::open(&db_path).unwrap();
+        assert_eq!(store.stats().parse_partial_files, 1);
+
+        store
+            .commit_batch(
+                vec![],
+                vec![FileBatchRemoval { path }],
+                redb::Durability::Immediate,
+            )
+            .unwrap();
+        assert_eq!(store.stats().parse_partial_files, 0);
+    }
+
+    #[test]
+    fn generated_files_are_rejected_before_parse() {
+        let tmp = tempfile::tempdir().unwrap();
+        let parsers = ParserPool::new();
+        let abs = tmp.path().join("bindings.rs");
+        std::fs::write(&abs, "// @generated by bindgen\npub fn ffi_call() {}\n").unwrap();
+        assert!(matches!(
+            parse_and_extract(&parsers, tmp.path(), &abs),
+            Err(ParseRejected::Generated(
+                crate::filter::GeneratedReason::Marker
+            ))
+        ));
+    }
+
     #[test]
     fn rescan_queues_orphan_for_removal_when_file_vanishes() {
         let (store, tmp) = seed_store_with_one_file();
@@ -110,6 +110,11 @@ enum Cmd {
         /// e.g. string literals containing a phrase.
         #[arg(long)]
         structural_query: Option<String>,
+        /// Read the `--structural-query` source from a `.scm` file —
+        /// for multi-pattern queries with predicates that are awkward to
+        /// quote on a command line.
+        #[arg(long, value_name = "FILE", conflicts_with = "structural_query")]
+        structural_query_file: Option<PathBuf>,
         /// Restrict matches to the byte range of the named symbol.
         #[arg(long)]
         within_symbol: Option<String>,
@@ -185,6 +190,8 @@ enum Cmd {
     /// CI usage:
     ///   `rts verify-edit --edits pr-edits.json --fail-on critical`
     /// fails the build on a caller-breaking patch.
+    /// Under GitHub Actions the verdict is also appended to
+    /// `$GITHUB_STEP_SUMMARY` as a markdown table.
     VerifyEdit {
         /// Path to the edits JSON, or `-` to read from stdin.
         #[arg(long)]
@@ -232,6 +239,13 @@ enum Cmd {
         /// Target shell. One of: bash, zsh, fish, powershell, elvish.
         shell: Shell,
     },
+    /// Write roff man pages for `rts` and each subcommand into DIR
+    /// (`rts.1`, `rts-grep.1`, …). For packagers: install them under
+    /// `share/man/man1`.
+    Manpages {
+        /// Output directory. Created if missing.
+        dir: PathBuf,
+    },
     /// Manage anonymous opt-in telemetry. See `docs/telemetry.md` for
     /// the full schema, retention policy, and privacy boundaries.
     /// Default is OFF; nothing is sent unless you explicitly enable.
@@ -270,7 +284,8 @@ enum Cmd {
         format: ClonesFormat,
     },
     /// Repo-level entropy snapshot: rev, loc, symbols, dup_pct,
-    /// clone_clusters (+ nullable fan-in/out and dependency counts).
+    /// clone_clusters, mean fan-in/out, doc_coverage_pct, per-language
+    /// files/loc (+ nullable dependency counts).
     /// Entropy-v0 contract; runs in-process over the workspace, no daemon.
     #[cfg(feature = "experimental")]
     Snapshot {
@@ -377,15 +392,47 @@ fn main() -> ExitCode {
         )
         .try_init();
 
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
     let style = Style::auto(cli.no_color);
 
+    // `--structural-query-file` is an argument, not a daemon concern:
+    // read it before any connect/auto-spawn so a bad path exits 2.
+    if let Cmd::Grep {
+        structural_query,
+        structural_query_file,
+        ..
+    } = &mut cli.cmd
+    {
+        if let Some(path) = structural_query_file.take() {
+            match std::fs::read_to_string(&path) {
+                Ok(q) => *structural_query = Some(q),
+                Err(e) => {
+                    eprintln!(
+                        "{}: read {}: {e}",
+                        style.red("rts grep error"),
+                        path.display()
+                    );
+                    return ExitCode::from(exit::USAGE as u8);
+                }
+            }
+        }
+    }
+
     // Top-level commands that don't talk to the daemon — handle before
     // we spin up a Tokio runtime.
     if let Cmd::Completions { shell } = &cli.cmd {
         emit_completions(*shell);
         return ExitCode::from(exit::OK as u8);
     }
+    if let Cmd::Manpages { dir } = &cli.cmd {
+        return match emit_manpages(dir) {
+            Ok(()) => ExitCode::from(exit::OK as u8),
+            Err(e) => {
+                eprintln!("{}: {e:#}", style.red("rts manpages error"));
+                ExitCode::from(exit::USAGE as u8)
+            }
+        };
+    }
     if let Cmd::Doctor { output } = &cli.cmd {
         return run_doctor(output.as_deref());
     }
@@ -608,6 +655,8 @@ async fn run_command(
             limit,
             multiline,
             structural_query,
+            // Already read into `structural_query` by `main`.
+            structural_query_file: _,
             within_symbol,
             within_symbol_allow_overload,
             language,
@@ -851,7 +900,10 @@ async fn run_command(
             Ok(exit::OK)
         }
         // Handled before reaching here.
-        Cmd::Doctor { .. } | Cmd::Completions { .. } | Cmd::Telemetry { .. } => Ok(exit::OK),
+        Cmd::Doctor { .. }
+        | Cmd::Completions { .. }
+        | Cmd::Manpages { .. }
+        | Cmd::Telemetry { .. } => Ok(exit::OK),
         // Entropy-v0 subcommands are handled synchronously in main().
         #[cfg(feature = "experimental")]
         Cmd::Context { .. } | Cmd::Clones { .. } | Cmd::Snapshot { .. } => Ok(exit::OK),
@@ -967,6 +1019,9 @@ async fn run_verify(
 /// Exit codes:
 ///   pass/warn/fail mapped through `--fail-on` → 0 or 2 (see [`FailOn`]).
 ///   malformed / missing edits, daemon contact failure → 3 (DAEMON_ERROR).
+///
+/// When `GITHUB_STEP_SUMMARY` is set the verdict is also appended there
+/// as markdown, regardless of `--json`.
 async fn run_verify_edit(
     client: &rts_mcp::connection::ConnectionManager,
     workspace: &std::path::Path,
@@ -1036,6 +1091,22 @@ async fn run_verify_edit(
         cli::render_edit_verdict(&body, &mut stdout, style).map_err(io_to_anyhow)?;
         stdout.flush().map_err(io_to_anyhow)?;
     }
+    // Inside GitHub Actions, also append a markdown verdict to the job
+    // summary. Best-effort: an unwritable summary never changes the gate.
+    if let Some(path) = std::env::var_os("GITHUB_STEP_SUMMARY").filter(|p| !p.is_empty()) {
+        let written = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut f| cli::render_edit_verdict_markdown(&body, &mut f));
+        if let Err(e) = written {
+            eprintln!(
+                "{}: GITHUB_STEP_SUMMARY {}: {e}",
+                style.yellow("warning"),
+                std::path::Path::new(&path).display()
+            );
+        }
+    }
 
     // 4. Gate on the verdict.
     let verdict = body
@@ -1416,6 +1487,29 @@ fn emit_completions(shell: Shell) {
     clap_complete::generate(shell, &mut cmd, "rts", &mut std::io::stdout());
 }
 
+/// Render `rts.1` plus one `rts-<sub>.1` per visible subcommand into
+/// `dir`. Nested subcommands (`telemetry status`, …) are covered by
+/// their parent's page.
+fn emit_manpages(dir: &std::path::Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir).map_err(|e| anyhow::anyhow!("create {}: {e}", dir.display()))?;
+    let mut cmd = Cli::command();
+    // `build` assigns each subcommand its `rts-<sub>` display name and
+    // `rts <sub>` bin name, which clap_mangen uses for NAME / SYNOPSIS.
+    cmd.build();
+    let pages = std::iter::once(&cmd).chain(
+        cmd.get_subcommands()
+            .filter(|s| !s.is_hide_set() && s.get_name() != "help"),
+    );
+    for page in pages {
+        let name = page.get_display_name().unwrap_or_else(|| page.get_name());
+        let path = dir.join(format!("{name}.1"));
+        let mut buf = Vec::new();
+        clap_mangen::Man::new(page.clone()).render(&mut buf)?;
+        std::fs::write(&path, buf).map_err(|e| anyhow::anyhow!("write {}: {e}", path.display()))?;
+    }
+    Ok(())
+}
+
 /// `rts doctor` → delegate to the `rts-bench` binary's `doctor`
 /// subcommand. We don't re-implement doctor inside `rts` because it
 /// already lives in rts-bench and the contract is a stable public API.
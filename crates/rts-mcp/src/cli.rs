@@ -18,13 +18,16 @@ use crate::socket;
 ///
 /// 0 — success with results.
 /// 1 — success with zero results (matches `rg`'s convention).
-/// 2 — clap-handled invalid argument (clap exits this itself).
+/// 2 — invalid argument: clap exits this itself for parse errors, and
+///     `rts` uses it for unusable path arguments (an unwritable
+///     `rts manpages` directory, a missing `--structural-query-file`).
 /// 3 — daemon-level error (JSON-RPC error envelope).
 /// 4 — request timeout.
 /// 5 — workspace resolution error (no marker found, path missing, etc.).
 pub mod exit {
     pub const OK: i32 = 0;
     pub const NO_RESULTS: i32 = 1;
+    pub const USAGE: i32 = 2;
     pub const DAEMON_ERROR: i32 = 3;
     pub const TIMEOUT: i32 = 4;
     pub const WORKSPACE_ERROR: i32 = 5;
@@ -620,19 +623,7 @@ pub fn render_edit_verdict<W: Write>(
     style: &Style,
 ) -> std::io::Result<usize> {
     let verdict = body.get("verdict").and_then(|v| v.as_str()).unwrap_or("?");
-    let summary = body.get("summary");
-    let crit = summary
-        .and_then(|s| s.get("critical"))
-        .and_then(|v| v.as_u64())
-        .unwrap_or(0);
-    let warn = summary
-        .and_then(|s| s.get("warning"))
-        .and_then(|v| v.as_u64())
-        .unwrap_or(0);
-    let info = summary
-        .and_then(|s| s.get("info"))
-        .and_then(|v| v.as_u64())
-        .unwrap_or(0);
+    let (crit, warn, info) = verdict_counts(body);
 
     let headline = match verdict {
         "pass" => style.green("PASS"),
@@ -661,48 +652,13 @@ pub fn render_edit_verdict<W: Write>(
         )?;
     }
 
-    let findings = body
-        .get("findings")
-        .and_then(|v| v.as_array())
-        .cloned()
-        .unwrap_or_default();
-
-    // Sort most-severe first (critical → warning → info), then by kind for
-    // a stable order.
-    let sev_rank = |s: &str| match s {
-        "critical" => 0,
-        "warning" => 1,
-        "info" => 2,
-        _ => 3,
-    };
-    let mut ordered: Vec<&Value> = findings.iter().collect();
-    ordered.sort_by(|a, b| {
-        let sa = a.get("severity").and_then(|v| v.as_str()).unwrap_or("");
-        let sb = b.get("severity").and_then(|v| v.as_str()).unwrap_or("");
-        sev_rank(sa).cmp(&sev_rank(sb)).then_with(|| {
-            let ka = a.get("kind").and_then(|v| v.as_str()).unwrap_or("");
-            let kb = b.get("kind").and_then(|v| v.as_str()).unwrap_or("");
-            ka.cmp(kb)
-        })
-    });
-
+    let ordered = findings_by_severity(body);
     for f in &ordered {
         let severity = f.get("severity").and_then(|v| v.as_str()).unwrap_or("?");
         let kind = f.get("kind").and_then(|v| v.as_str()).unwrap_or("?");
         let symbol = f.get("symbol").and_then(|v| v.as_str()).unwrap_or("");
         let detail = f.get("detail").and_then(|v| v.as_str()).unwrap_or("");
-        let site = f.get("site");
-        let site_str = site
-            .map(|s| {
-                let file = s.get("file").and_then(|v| v.as_str()).unwrap_or("");
-                let enclosing = s.get("enclosing").and_then(|v| v.as_str());
-                match enclosing {
-                    Some(e) if !e.is_empty() => format!("{file}:{e}"),
-                    _ => file.to_string(),
-                }
-            })
-            .filter(|s| !s.is_empty())
-            .unwrap_or_default();
+        let site_str = finding_site(f);
 
         let sev_colored = match severity {
             "critical" => style.red("CRITICAL"),
@@ -726,6 +682,102 @@ pub fn render_edit_verdict<W: Write>(
     Ok(ordered.len())
 }
 
+/// Render an `Index.VerifyEdit` verdict as GitHub-flavoured markdown:
+/// a headline plus one table row per finding, most severe first. Used
+/// for `$GITHUB_STEP_SUMMARY`, so there is no ANSI and `|` in cell
+/// text is escaped. Returns the number of findings written.
+pub fn render_edit_verdict_markdown<W: Write>(body: &Value, w: &mut W) -> std::io::Result<usize> {
+    let verdict = body.get("verdict").and_then(|v| v.as_str()).unwrap_or("?");
+    let (crit, warn, info) = verdict_counts(body);
+    writeln!(
+        w,
+        "### rts verify-edit: {} ({crit} critical, {warn} warning, {info} info)",
+        verdict.to_uppercase()
+    )?;
+    let skipped = body
+        .get("files_skipped")
+        .and_then(|v| v.as_array())
+        .map(|a| a.len())
+        .unwrap_or(0);
+    if skipped > 0 {
+        writeln!(
+            w,
+            "\n> {skipped} file(s) skipped (over the analysis cap — partial result)"
+        )?;
+    }
+
+    let ordered = findings_by_severity(body);
+    if !ordered.is_empty() {
+        writeln!(w, "\n| Severity | Kind | Symbol | Site | Detail |")?;
+        writeln!(w, "|---|---|---|---|---|")?;
+    }
+    let cell = |s: &str| s.replace('|', "\\|").replace('\n', " ");
+    for f in &ordered {
+        let field = |k: &str| f.get(k).and_then(|v| v.as_str()).unwrap_or("");
+        writeln!(
+            w,
+            "| {} | `{}` | `{}` | {} | {} |",
+            cell(field("severity")),
+            cell(field("kind")),
+            cell(field("symbol")),
+            cell(&finding_site(f)),
+            cell(field("detail")),
+        )?;
+    }
+    Ok(ordered.len())
+}
+
+/// `(critical, warning, info)` from a verdict's `summary` block.
+fn verdict_counts(body: &Value) -> (u64, u64, u64) {
+    let summary = body.get("summary");
+    let count = |k: &str| {
+        summary
+            .and_then(|s| s.get(k))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+    };
+    (count("critical"), count("warning"), count("info"))
+}
+
+/// A verdict's findings sorted most-severe first (critical → warning →
+/// info), then by kind for a stable order.
+fn findings_by_severity(body: &Value) -> Vec<&Value> {
+    let sev_rank = |s: &str| match s {
+        "critical" => 0,
+        "warning" => 1,
+        "info" => 2,
+        _ => 3,
+    };
+    let mut ordered: Vec<&Value> = body
+        .get("findings")
+        .and_then(|v| v.as_array())
+        .map(|a| a.iter().collect())
+        .unwrap_or_default();
+    ordered.sort_by(|a, b| {
+        let sa = a.get("severity").and_then(|v| v.as_str()).unwrap_or("");
+        let sb = b.get("severity").and_then(|v| v.as_str()).unwrap_or("");
+        sev_rank(sa).cmp(&sev_rank(sb)).then_with(|| {
+            let ka = a.get("kind").and_then(|v| v.as_str()).unwrap_or("");
+            let kb = b.get("kind").and_then(|v| v.as_str()).unwrap_or("");
+            ka.cmp(kb)
+        })
+    });
+    ordered
+}
+
+/// `file:enclosing` (or bare `file`) for a finding's `site`; empty when
+/// the finding has no site.
+fn finding_site(f: &Value) -> String {
+    let Some(site) = f.get("site") else {
+        return String::new();
+    };
+    let file = site.get("file").and_then(|v| v.as_str()).unwrap_or("");
+    match site.get("enclosing").and_then(|v| v.as_str()) {
+        Some(e) if !e.is_empty() => format!("{file}:{e}"),
+        _ => file.to_string(),
+    }
+}
+
 /// Render the daemon's `outline_text` directly. The daemon already
 /// produces a dotted-indent tree-style hierarchy (protocol-v0 §7.5);
 /// we just pass it through (with a header) so the CLI shape stays
@@ -1063,6 +1115,42 @@ mod tests {
         assert!(!s.contains('\x1b'), "no_color must suppress ANSI: {s:?}");
     }
 
+    #[test]
+    fn edit_verdict_markdown_renders_table_and_escapes_pipes() {
+        let body = json!({
+            "verdict": "warn",
+            "summary": { "critical": 0, "warning": 1, "info": 1 },
+            "findings": [
+                { "severity": "info", "kind": "new_symbol", "symbol": "brand_new",
+                  "site": { "file": "hub.rs" }, "detail": "added" },
+                { "severity": "warning", "kind": "signature_break", "symbol": "target",
+                  "site": { "file": "lib.rs", "enclosing": "caller" },
+                  "detail": "fn(a) | fn(a, b)" },
+            ],
+        });
+        let mut buf = Vec::new();
+        let n = render_edit_verdict_markdown(&body, &mut buf).unwrap();
+        assert_eq!(n, 2);
+        let s = String::from_utf8(buf).unwrap();
+        assert!(
+            s.starts_with("### rts verify-edit: WARN (0 critical, 1 warning, 1 info)"),
+            "{s:?}"
+        );
+        assert!(
+            s.contains("| Severity | Kind | Symbol | Site | Detail |"),
+            "{s:?}"
+        );
+        let warn_idx = s.find("| warning |").expect("warning row");
+        let info_idx = s.find("| info |").expect("info row");
+        assert!(warn_idx < info_idx, "warning must sort first: {s:?}");
+        assert!(s.contains("lib.rs:caller"), "site: {s:?}");
+        assert!(
+            s.contains(r"fn(a) \| fn(a, b)"),
+            "pipe must be escaped: {s:?}"
+        );
+        assert!(!s.contains('\x1b'), "{s:?}");
+    }
+
     #[test]
     fn detect_workspace_finds_marker() {
         let tmp = tempfile::tempdir().unwrap();
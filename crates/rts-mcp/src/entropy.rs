@@ -9,15 +9,22 @@
 //!
 //! Output shapes are frozen by the golden fixtures in the entropy starter
 //! repo (`fixtures/rts/*.json`); see `docs/entropy/rts-brief.md` there.
+//! Frozen means the contract keys keep their names, types and nullability.
+//! `rts snapshot` values (`rev`, `loc`, …) differ per repo, so its fixture
+//! pins keys rather than bytes, and rts may add keys beyond the contract
+//! (`doc_coverage_pct`, `languages`), never rename or remove one.
 //! Experimental surface (see AGENTS.md "Experimental surface gate").
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 use std::process::Command;
 
 use serde_json::{Value, json};
 
-use rust_tree_sitter::{Language, detect_language_from_path, parse_content, signature};
+use rust_tree_sitter::{
+    Language, RefKind, detect_language_from_path, extract_references, parse_content, signature,
+    supports_references,
+};
 
 // ---------- workspace scan ----------
 
@@ -592,17 +599,30 @@ fn hex8(bytes: &[u8; 8]) -> String {
 
 // ---------- snapshot ----------
 
-/// `rts snapshot --format json` — repo-level entropy stats. The four
-/// dependency/fan metrics are nullable per the contract and rts doesn't
+/// `rts snapshot --format json` — repo-level entropy stats. Fan-in/out
+/// come from [`CallGraph`], doc coverage from [`expects_doc`], and
+/// `languages` breaks files/loc down by language name; the two
+/// dependency counts are nullable per the contract and rts doesn't
 /// compute them yet, so they're emitted as `null`.
 pub fn run_snapshot(workspace: &Path, min_mass_tokens: usize) -> i32 {
     let files = scan_files(workspace);
     let loc: usize = files.iter().map(|f| f.content.lines().count()).sum();
-    let symbols: usize = files
-        .iter()
-        .filter_map(|f| parse_content(&f.content, f.language).ok())
-        .map(|o| o.symbols.len())
-        .sum();
+    let languages = language_breakdown(&files);
+    let mut all_symbols = Vec::new();
+    let mut graph = CallGraph::default();
+    for f in &files {
+        let Ok(outcome) = parse_content(&f.content, f.language) else {
+            continue;
+        };
+        graph.add_file(f, &outcome.symbols);
+        all_symbols.extend(outcome.symbols);
+    }
+    let symbols = all_symbols.len();
+    let doc_coverage_pct = doc_coverage_pct(&all_symbols);
+    let (mean_fan_in, mean_fan_out) = match graph.mean_fan() {
+        Some((fan_in, fan_out)) => (json!(round2(fan_in)), json!(round2(fan_out))),
+        None => (Value::Null, Value::Null),
+    };
 
     let report = detect_clones(workspace, min_mass_tokens);
     let dup_tokens: usize = report
@@ -633,14 +653,119 @@ pub fn run_snapshot(workspace: &Path, min_mass_tokens: usize) -> i32 {
         "symbols": symbols,
         "dup_pct": round2(dup_pct),
         "clone_clusters": report.clusters.len(),
-        "mean_fan_in": Value::Null,
-        "mean_fan_out": Value::Null,
+        "mean_fan_in": mean_fan_in,
+        "mean_fan_out": mean_fan_out,
+        "doc_coverage_pct": doc_coverage_pct,
+        "languages": languages,
         "deps_direct": Value::Null,
         "deps_transitive": Value::Null,
     }));
     0
 }
 
+/// `{ <Language::name()>: { "files", "loc" } }`, keyed in name order.
+fn language_breakdown(files: &[FileEntry]) -> Value {
+    let mut languages: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for f in files {
+        let entry = languages.entry(f.language.name()).or_default();
+        entry.0 += 1;
+        entry.1 += f.content.lines().count();
+    }
+    languages
+        .into_iter()
+        .map(|(name, (files, loc))| (name.to_string(), json!({ "files": files, "loc": loc })))
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+/// Percentage of [`expects_doc`] symbols that carry documentation, or
+/// `null` when there are none.
+fn doc_coverage_pct(symbols: &[rust_tree_sitter::Symbol]) -> Value {
+    let (mut public, mut documented) = (0usize, 0usize);
+    for s in symbols.iter().filter(|s| expects_doc(s)) {
+        public += 1;
+        documented += usize::from(s.documentation.is_some());
+    }
+    if public == 0 {
+        Value::Null
+    } else {
+        json!(round2(100.0 * documented as f64 / public as f64))
+    }
+}
+
+/// Whether `s` counts toward `doc_coverage_pct`: public symbols, minus
+/// `impl` blocks and `let` bindings, which never carry docs, and Markdown
+/// headings, which are prose rather than API.
+fn expects_doc(s: &rust_tree_sitter::Symbol) -> bool {
+    s.visibility == "public" && !matches!(s.kind.as_str(), "impl" | "variable" | "heading")
+}
+
+/// Name-level call graph over the languages with reference extraction
+/// ([`supports_references`]). Nodes are function/method names; `a → b`
+/// when a call to `b` sits inside `a`'s line span and `b` is defined
+/// somewhere in the workspace. Same-named functions share a node —
+/// imprecise per symbol, fine for a repo-level mean.
+#[derive(Default)]
+struct CallGraph {
+    defined: HashSet<String>,
+    calls: HashSet<(String, String)>,
+}
+
+impl CallGraph {
+    fn add_file(&mut self, file: &FileEntry, symbols: &[rust_tree_sitter::Symbol]) {
+        if !supports_references(file.language) {
+            return;
+        }
+        let fns: Vec<_> = symbols
+            .iter()
+            .filter(|s| s.kind == "function" || s.kind == "method")
+            .collect();
+        self.defined.extend(fns.iter().map(|s| s.name.clone()));
+        for r in extract_references(file.content.as_bytes(), file.language) {
+            if r.kind != RefKind::Call {
+                continue;
+            }
+            // Innermost enclosing function: the containing span that
+            // starts last.
+            let caller = fns
+                .iter()
+                .filter(|s| s.start_line <= r.line && r.line <= s.end_line)
+                .max_by_key(|s| s.start_line);
+            if let Some(caller) = caller {
+                if caller.name != r.name {
+                    self.calls.insert((caller.name.clone(), r.name));
+                }
+            }
+        }
+    }
+
+    /// `(mean_fan_in, mean_fan_out)`, or `None` when no internal call
+    /// edges were found. Fan-out is averaged over functions that make at
+    /// least one internal call and fan-in over functions called at least
+    /// once — over the full node set both would collapse to the same
+    /// edges/nodes ratio.
+    fn mean_fan(&self) -> Option<(f64, f64)> {
+        let mut fan_in: HashMap<&str, usize> = HashMap::new();
+        let mut fan_out: HashMap<&str, usize> = HashMap::new();
+        let mut edges = 0usize;
+        for (caller, callee) in &self.calls {
+            if !self.defined.contains(callee) {
+                continue;
+            }
+            edges += 1;
+            *fan_in.entry(callee.as_str()).or_default() += 1;
+            *fan_out.entry(caller.as_str()).or_default() += 1;
+        }
+        if edges == 0 {
+            return None;
+        }
+        Some((
+            edges as f64 / fan_in.len() as f64,
+            edges as f64 / fan_out.len() as f64,
+        ))
+    }
+}
+
 // ---------- shared ----------
 
 fn round2(x: f64) -> f64 {
@@ -653,3 +778,92 @@ fn print_json(value: &Value) {
         serde_json::to_string_pretty(value).unwrap_or_default()
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_graph_fan_means_count_internal_edges_only() {
+        let file = FileEntry {
+            path: "lib.rs".into(),
+            content: "fn a() { b(); c(); external(); }\n\
+                      fn b() { c(); b(); }\n\
+                      fn c() {}\n\
+                      fn d() { c(); }\n"
+                .into(),
+            language: Language::Rust,
+        };
+        let outcome = parse_content(&file.content, file.language).unwrap();
+        let mut graph = CallGraph::default();
+        graph.add_file(&file, &outcome.symbols);
+
+        // Edges: a→b, a→c, b→c, d→c (self-recursion and `external`
+        // dropped). Fan-in over {b, c} = 4/2; fan-out over {a, b, d} = 4/3.
+        let (fan_in, fan_out) = graph.mean_fan().expect("edges found");
+        assert_eq!(round2(fan_in), 2.0);
+        assert_eq!(round2(fan_out), 1.33);
+    }
+
+    #[test]
+    fn call_graph_without_edges_is_none() {
+        assert!(CallGraph::default().mean_fan().is_none());
+    }
+
+    #[test]
+    fn doc_coverage_counts_public_items_only() {
+        let src = "/// Documented.\n#[derive(Debug)]\npub struct A;\n\
+                   impl A {}\n\
+                   pub fn undocumented() { let x = 1; }\n\
+                   fn private() {}\n";
+        let outcome = parse_content(src, Language::Rust).unwrap();
+        let mut candidates: Vec<_> = outcome
+            .symbols
+            .iter()
+            .filter(|s| expects_doc(s))
+            .map(|s| (s.name.as_str(), s.documentation.is_some()))
+            .collect();
+        candidates.sort();
+        assert_eq!(candidates, [("A", true), ("undocumented", false)]);
+    }
+
+    #[test]
+    fn language_breakdown_counts_files_and_loc_per_language() {
+        let file = |path: &str, content: &str, language| FileEntry {
+            path: path.into(),
+            content: content.into(),
+            language,
+        };
+        let files = [
+            file("src/lib.rs", "fn a() {}\nfn b() {}\n", Language::Rust),
+            file("src/main.rs", "fn main() {}\n", Language::Rust),
+            file(
+                "tools/gen.py",
+                "import os\n\ndef f():\n    pass\n",
+                Language::Python,
+            ),
+        ];
+        assert_eq!(
+            language_breakdown(&files),
+            json!({
+                "Python": { "files": 1, "loc": 4 },
+                "Rust": { "files": 2, "loc": 3 },
+            })
+        );
+    }
+
+    #[test]
+    fn doc_coverage_ignores_markdown_headings() {
+        let mut symbols = parse_content("pub fn a() {}\npub fn b() {}\n", Language::Rust)
+            .unwrap()
+            .symbols;
+        let readme = "# Project\n\nIntro.\n\n## Usage\n\nRun it.\n\n## License\n\nMIT.\n";
+        let headings = parse_content(readme, Language::Markdown).unwrap().symbols;
+        assert!(
+            headings.iter().any(|s| s.kind == "heading"),
+            "fixture should produce heading symbols: {headings:?}"
+        );
+        symbols.extend(headings);
+        assert_eq!(doc_coverage_pct(&symbols), json!(0.0));
+    }
+}
@@ -149,6 +149,80 @@ async fn grep_structural_identifier_usages() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn grep_structural_query_file_matches_inline_query() {
+    let env = TestEnv::new();
+    seed_minimal_rust_workspace(env.workspace_path());
+
+    // Same search as `grep_structural_identifier_usages`, with the query
+    // read from a `.scm` file (comments allowed) instead of inline.
+    let query_dir = tempfile::tempdir().unwrap();
+    let query_path = query_dir.path().join("idents.scm");
+    std::fs::write(&query_path, "; every identifier node\n(identifier) @i\n").unwrap();
+
+    let inline = env
+        .run(&[
+            "--no-color",
+            "grep",
+            "make_widget",
+            "--structural-query",
+            "(identifier) @i",
+            "--language",
+            "rust",
+        ])
+        .await;
+    let from_file = env
+        .run(&[
+            "--no-color",
+            "grep",
+            "make_widget",
+            "--structural-query-file",
+            query_path.to_str().unwrap(),
+            "--language",
+            "rust",
+        ])
+        .await;
+    let (inline_out, _, inline_code) = parts(&inline);
+    let (file_out, file_err, file_code) = parts(&from_file);
+    assert_eq!(file_code, 0, "stdout={file_out:?} stderr={file_err:?}");
+    assert_eq!(inline_code, 0);
+    assert_eq!(file_out, inline_out, "file and inline queries must agree");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn grep_missing_structural_query_file_is_a_usage_error() {
+    let env = TestEnv::new();
+    seed_minimal_rust_workspace(env.workspace_path());
+
+    let missing = env.workspace_path().join("no-such-query.scm");
+    let out = env
+        .run(&[
+            "grep",
+            "--structural-query-file",
+            missing.to_str().unwrap(),
+            "--language",
+            "rust",
+        ])
+        .await;
+    let (stdout, stderr, code) = parts(&out);
+    assert_eq!(
+        code, 2,
+        "missing query file should exit 2; stdout={stdout:?} stderr={stderr:?}"
+    );
+    assert!(
+        stderr.contains("rts grep error") && stderr.contains("no-such-query.scm"),
+        "stderr should name the unreadable file; got {stderr:?}"
+    );
+    // The path is checked before connecting, so no daemon was spawned.
+    assert!(
+        std::fs::read_dir(env.runtime.path())
+            .unwrap()
+            .next()
+            .is_none(),
+        "no daemon socket should appear in the runtime dir"
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn grep_structural_limit_does_not_truncate_before_filter() {
     // Regression for #147. `format_widget` is the *last* fn def in
@@ -0,0 +1,40 @@
+//! `rts manpages <DIR>` — roff pages for packagers. No daemon involved.
+
+mod cli_common;
+
+use cli_common::{TestEnv, parts};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn manpages_writes_root_and_subcommand_pages() {
+    let env = TestEnv::new();
+    let dir = env.workspace_path().join("man/man1");
+    let out = env
+        .run(&["manpages", dir.to_str().expect("utf-8 tempdir")])
+        .await;
+    let (stdout, stderr, code) = parts(&out);
+    assert_eq!(
+        code, 0,
+        "manpages should exit 0; stdout={stdout:?} stderr={stderr:?}"
+    );
+    for page in ["rts.1", "rts-grep.1"] {
+        let body = std::fs::read_to_string(dir.join(page))
+            .unwrap_or_else(|e| panic!("{page} should be written: {e}"));
+        assert!(body.contains(".TH"), "{page} should be roff; got {body:?}");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn manpages_into_a_file_path_is_a_usage_error() {
+    let env = TestEnv::new();
+    let file = env.workspace_path().join("not-a-dir");
+    std::fs::write(&file, "").unwrap();
+    let out = env
+        .run(&["manpages", file.to_str().expect("utf-8 tempdir")])
+        .await;
+    let (_stdout, stderr, code) = parts(&out);
+    assert_eq!(code, 2, "unusable DIR should exit 2; stderr={stderr:?}");
+    assert!(
+        stderr.contains("rts manpages error"),
+        "stderr should name the command; got {stderr:?}"
+    );
+}
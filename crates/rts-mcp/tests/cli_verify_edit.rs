@@ -216,3 +216,51 @@ async fn json_flag_passes_daemon_response_through() {
         "--json passes the daemon verdict through; got {stdout:?}"
     );
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn step_summary_gets_markdown_verdict_appended() {
+    let env = TestEnv::new();
+    seed_target_caller(&env);
+    wait_until_refs_ready(&env).await;
+
+    let edits = write_edits(
+        &env,
+        "edits.json",
+        r#"[{"file":"hub.rs","content":"pub fn unrelated() -> u32 { 0 }\n"}]"#,
+    );
+    let summary = env.workspace_path().join("step-summary.md");
+    std::fs::write(&summary, "existing\n").unwrap();
+
+    let out = env
+        .run_with_env(
+            &[
+                "--no-color",
+                "verify-edit",
+                "--edits",
+                edits.to_str().unwrap(),
+            ],
+            "GITHUB_STEP_SUMMARY",
+            summary.to_str().unwrap(),
+        )
+        .await;
+    let (stdout, stderr, code) = parts(&out);
+    assert_eq!(
+        code, 2,
+        "summary must not change the gate; stderr={stderr:?}"
+    );
+    assert!(
+        !stdout.contains("| Severity |"),
+        "markdown goes to the file, not stdout"
+    );
+
+    let md = std::fs::read_to_string(&summary).unwrap();
+    assert!(
+        md.starts_with("existing\n"),
+        "must append, not truncate: {md:?}"
+    );
+    assert!(md.contains("### rts verify-edit: FAIL"), "{md:?}");
+    assert!(
+        md.contains("| critical |") && md.contains("`target`"),
+        "broken caller row missing: {md:?}"
+    );
+}